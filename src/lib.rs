@@ -1,4 +1,9 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
 use std::marker::PhantomData;
+use std::ptr::NonNull;
 
 // this module adds some functionality based on the required implementations
 // here like: `LinkedList::pop_back` or `Clone for LinkedList<T>`
@@ -7,14 +12,25 @@ mod pre_implemented;
 
 pub struct LinkedList<T> {
     len: usize,
-    head: Option<*mut Node<T>>,
-    tail: Option<*mut Node<T>>,
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    // `LinkedList<T>` owns every `Node<T>` it points to (through a `Box` at
+    // construction time), so this tells the drop checker and variance
+    // analysis to treat it as if it held `Box<Node<T>>` directly, even
+    // though the actual fields are raw, non-owning pointers.
+    marker: PhantomData<Box<Node<T>>>,
 }
 
+// The raw pointers are private and only ever followed while `self`/`&mut
+// self` is held, so `LinkedList<T>` is safe to send or share across
+// threads whenever `T` itself is, exactly like the nodes it owns.
+unsafe impl<T: Send> Send for LinkedList<T> {}
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
 pub struct Node<T> {
     value: T,
-    next: Option<*mut Node<T>>,
-    prev: Option<*mut Node<T>>,
+    next: Option<NonNull<Node<T>>>,
+    prev: Option<NonNull<Node<T>>>,
 }
 
 impl<T> Node<T> {
@@ -29,25 +45,32 @@ impl<T> Node<T> {
     // This will be called only on a valid and existing node.
     // The result of this is that the optionally new nodes and/or previous nodes will be linked
     // ensuring double-links prev <--> new_node <--> next depending on cursor positions.
-    unsafe fn link_nodes(&mut self, next: Option<*mut Node<T>>, prev: Option<*mut Node<T>>) {
+    unsafe fn link_nodes(&mut self, next: Option<NonNull<Node<T>>>, prev: Option<NonNull<Node<T>>>) {
         self.link_next(next);
         self.link_prev(prev)
     }
 
-    unsafe fn link_next(&mut self, next: Option<*mut Node<T>>) {
+    unsafe fn link_next(&mut self, next: Option<NonNull<Node<T>>>) {
         self.next = next;
     }
 
-    unsafe fn link_prev(&mut self, prev: Option<*mut Node<T>>) {
+    unsafe fn link_prev(&mut self, prev: Option<NonNull<Node<T>>>) {
         self.prev = prev;
     }
 }
 
 pub struct Cursor<'a, T> {
-    node: Option<*mut Node<T>>,
+    node: Option<NonNull<Node<T>>>,
     list: &'a mut LinkedList<T>,
+    // Distance of `node` from the front, maintained incrementally by every
+    // operation that can move or shift the cursor so that `index()` never
+    // has to walk the list.
+    index: Option<usize>,
 }
 
+unsafe impl<'a, T: Send> Send for Cursor<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Cursor<'a, T> {}
+
 impl<T> Default for LinkedList<T> {
     fn default() -> Self {
         Self::new()
@@ -60,6 +83,7 @@ impl<T> LinkedList<T> {
             len: 0,
             head: None,
             tail: None,
+            marker: PhantomData,
         }
     }
 
@@ -78,25 +102,21 @@ impl<T> LinkedList<T> {
 
     /// Return a cursor positioned on the front element
     pub fn cursor_front(&mut self) -> Cursor<T> {
+        let index = self.head.map(|_| 0);
         Cursor {
-            node: if self.head.is_some() {
-                Some(self.head.unwrap())
-            } else {
-                None
-            },
+            node: self.head,
             list: self,
+            index,
         }
     }
 
     /// Return a cursor positioned on the back element
     pub fn cursor_back(&mut self) -> Cursor<T> {
+        let index = self.tail.map(|_| self.len - 1);
         Cursor {
-            node: if self.tail.is_some() {
-                Some(self.tail.unwrap())
-            } else {
-                None
-            },
+            node: self.tail,
             list: self,
+            index,
         }
     }
 
@@ -104,9 +124,118 @@ impl<T> LinkedList<T> {
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             next: self.head,
+            next_back: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return an iterator of mutable references that moves from front to back
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head,
+            next_back: self.tail,
+            len: self.len,
             marker: PhantomData,
         }
     }
+
+    /// Move all of `other`'s nodes onto the back of `self`, leaving `other` empty.
+    /// This is O(1): no nodes are visited, only the boundary pointers are relinked.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        match self.tail {
+            // An empty `self` just becomes `other`; nothing to splice.
+            None => std::mem::swap(self, other),
+            Some(tail) => unsafe {
+                if let Some(other_head) = other.head {
+                    let other_tail = other.tail.unwrap();
+                    let other_len = other.len;
+
+                    self.splice_nodes(Some(tail), None, other_head, other_tail, other_len);
+
+                    // `other` no longer owns any nodes, so its `Drop` becomes a no-op.
+                    other.head = None;
+                    other.tail = None;
+                    other.len = 0;
+                }
+            },
+        }
+    }
+
+    /// Split the list into two at the given index, returning the tail half
+    /// as a new list and leaving the elements before `at` in `self`.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        let len = self.len;
+        assert!(at <= len, "Cannot split off at a nonexistent index");
+
+        if at == 0 {
+            return std::mem::take(self);
+        }
+        if at == len {
+            return LinkedList::new();
+        }
+
+        unsafe {
+            // Walk from whichever end is closer to the split point so that
+            // at most `len / 2` nodes are ever visited.
+            let mut split_node = if at <= len / 2 {
+                let mut node = self.head.unwrap();
+                for _ in 1..at {
+                    node = node.as_ref().next.unwrap();
+                }
+                node
+            } else {
+                let mut node = self.tail.unwrap();
+                for _ in 0..len - at {
+                    node = node.as_ref().prev.unwrap();
+                }
+                node
+            };
+
+            // `split_node` is the last node that stays in `self`; the run from
+            // `split_start` to the old tail becomes the returned list.
+            let mut split_start = split_node.as_ref().next.unwrap();
+            let old_tail = self.tail.unwrap();
+
+            split_node.as_mut().next = None;
+            split_start.as_mut().prev = None;
+
+            self.tail = Some(split_node);
+            self.len = at;
+
+            let mut tail_list = LinkedList::new();
+            tail_list.splice_nodes(None, None, split_start, old_tail, len - at);
+            tail_list
+        }
+    }
+
+    // Relinks the run of nodes `splice_start..=splice_end` in between
+    // `existing_prev` and `existing_next`, which must already belong to
+    // `self` (or be `None` to mean "the very front/back of the list").
+    // Only `next`/`prev` fields are touched, so no node's `value` is
+    // aliased while the splice happens.
+    unsafe fn splice_nodes(
+        &mut self,
+        existing_prev: Option<NonNull<Node<T>>>,
+        existing_next: Option<NonNull<Node<T>>>,
+        mut splice_start: NonNull<Node<T>>,
+        mut splice_end: NonNull<Node<T>>,
+        splice_length: usize,
+    ) {
+        match existing_prev {
+            Some(mut prev) => prev.as_mut().next = Some(splice_start),
+            None => self.head = Some(splice_start),
+        }
+        splice_start.as_mut().prev = existing_prev;
+
+        match existing_next {
+            Some(mut next) => next.as_mut().prev = Some(splice_end),
+            None => self.tail = Some(splice_end),
+        }
+        splice_end.as_mut().next = existing_next;
+
+        self.len += splice_length;
+    }
 }
 
 // the cursor is expected to act as if it is at the position of an element
@@ -115,7 +244,7 @@ impl<'a, T> Cursor<'a, T> {
     /// Take a mutable reference to the current element
     pub fn peek_mut(&mut self) -> Option<&mut T> {
         // The pointer does not get dereferenced unless the node exists.
-        unsafe { Some(&mut (*self.node?).value) }
+        unsafe { Some(&mut self.node?.as_mut().value) }
     }
 
     /// Move one position forward (towards the back) and
@@ -125,11 +254,17 @@ impl<'a, T> Cursor<'a, T> {
         match self.node.take() {
             Some(current) => unsafe {
                 // We shift the pointer to the next node if it is present.
-                self.node = (*current).next;
+                self.node = current.as_ref().next;
+                self.index = if self.node.is_some() {
+                    self.index.map(|i| i + 1)
+                } else {
+                    None
+                };
                 self.peek_mut()
             },
             None => {
                 self.node = self.list.head;
+                self.index = self.node.map(|_| 0);
                 None
             }
         }
@@ -141,16 +276,50 @@ impl<'a, T> Cursor<'a, T> {
         match self.node.take() {
             Some(current) => unsafe {
                 // We shift the pointer to the previous node if it is present.
-                self.node = (*current).prev;
+                self.node = current.as_ref().prev;
+                self.index = if self.node.is_some() {
+                    self.index.map(|i| i - 1)
+                } else {
+                    None
+                };
                 self.peek_mut()
             },
             None => {
                 self.node = self.list.tail;
+                self.index = self.node.map(|_| self.list.len - 1);
                 None
             }
         }
     }
 
+    /// Return the current position of the cursor from the front of the
+    /// list, or `None` at the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Move the cursor forward by `n` positions, stopping at the ghost
+    /// position rather than wrapping back around to the front.
+    pub fn seek_forward(&mut self, n: usize) {
+        for _ in 0..n {
+            if self.node.is_none() {
+                break;
+            }
+            self.next();
+        }
+    }
+
+    /// Move the cursor backward by `n` positions, stopping at the ghost
+    /// position rather than wrapping back around to the back.
+    pub fn seek_backward(&mut self, n: usize) {
+        for _ in 0..n {
+            if self.node.is_none() {
+                break;
+            }
+            self.prev();
+        }
+    }
+
     /// Remove and return the element at the current position and move the cursor
     /// to the neighboring element that's closest to the back. This can be
     /// either the next or previous position.
@@ -160,7 +329,7 @@ impl<'a, T> Cursor<'a, T> {
             let node = self.node?;
 
             // When the node exists it is moved de-allocating memory from the pointer.
-            let moved_node = std::boxed::Box::from_raw(node);
+            let moved_node = std::boxed::Box::from_raw(node.as_ptr());
 
             // The next and prev of the moved nodes get disconnected...
             let next = moved_node.next;
@@ -170,17 +339,29 @@ impl<'a, T> Cursor<'a, T> {
 
             // ... and then get reconnected accordingly.
             match next {
-                Some(mut next) => (*next).prev = prev,
+                Some(mut next) => next.as_mut().prev = prev,
                 None => self.list.tail = prev,
             };
 
             match prev {
-                Some(mut prev) => (*prev).next = next,
+                Some(mut prev) => prev.as_mut().next = next,
                 None => self.list.head = next,
             };
 
             self.list.len -= 1;
 
+            // If we moved onto `next`, the node that used to follow the
+            // removed one, it now sits at the same index; if we fell back
+            // to `prev` instead, our index just decreased by one; if
+            // neither exists the list is empty and we're at the ghost.
+            self.index = if next.is_some() {
+                self.index
+            } else if prev.is_some() {
+                self.index.map(|i| i - 1)
+            } else {
+                None
+            };
+
             Some(moved_node.value)
         }
     }
@@ -190,34 +371,39 @@ impl<'a, T> Cursor<'a, T> {
     // of the cursor gets its prev pointer pointing at the new node.
     pub fn insert_after(&mut self, element: T) {
         unsafe {
-            // If the cursor node does not exist, it is an empty list
-            // so we insert the first node and return.
-            let Some(cursor_node) = self.node else  {
-                self.insert_first(element);
+            let Some(mut cursor_node) = self.node else {
+                if self.list.is_empty() {
+                    // A genuinely empty list: the new node becomes the
+                    // first (and only) element, and the cursor sits on it.
+                    self.insert_first(element);
+                } else {
+                    // The cursor walked off the end of a non-empty list.
+                    // The ghost's `next` is the head, so inserting after
+                    // it splices the new node in at the very front without
+                    // moving the cursor off the ghost.
+                    let new_node = NonNull::new_unchecked(Box::into_raw(Box::new(Node::new(element))));
+                    let old_head = self.list.head;
+                    self.list.splice_nodes(None, old_head, new_node, new_node, 1);
+                }
                 return;
             };
 
-            // Unwrap is asserted to return `Some(&node)` from the cursor node.
-            let &Node {
-                value: _,
-                next,
-                prev: _,
-            } = cursor_node.as_ref().unwrap();
+            let next = cursor_node.as_ref().next;
 
             // The new node is created on the heap and is linked to
             // its prev and next nodes.
             let mut ptr = Box::new(Node::new(element));
             ptr.as_mut().link_nodes(next, Some(cursor_node));
 
-            let new_node = std::boxed::Box::<Node<T>>::into_raw(ptr);
+            let new_node = NonNull::new_unchecked(Box::into_raw(ptr));
 
             // The new node is inserted after the cursor so it becomes the cursor's `next`.
-            (*cursor_node).next = Some(new_node);
+            cursor_node.as_mut().next = Some(new_node);
 
             // The cursor's former next node becomes linked to the new node,
             // with the new node being the prev.
-            if let Some(next_node) = next {
-                (*next_node).link_prev(Some(new_node));
+            if let Some(mut next_node) = next {
+                next_node.as_mut().link_prev(Some(new_node));
             }
 
             // When insert_after is called while the cursor is at the tail,
@@ -236,34 +422,39 @@ impl<'a, T> Cursor<'a, T> {
     // of the cursor gets its next pointer pointing at the new node.
     pub fn insert_before(&mut self, element: T) {
         unsafe {
-            // If the cursor node does not exist, it is an empty list
-            // so we insert the first node and return.
-            let Some(cursor_node) = self.node else  {
-                self.insert_first(element);
+            let Some(mut cursor_node) = self.node else {
+                if self.list.is_empty() {
+                    // A genuinely empty list: the new node becomes the
+                    // first (and only) element, and the cursor sits on it.
+                    self.insert_first(element);
+                } else {
+                    // The cursor walked off the end of a non-empty list.
+                    // The ghost's `prev` is the tail, so inserting before
+                    // it splices the new node in at the very back without
+                    // moving the cursor off the ghost.
+                    let new_node = NonNull::new_unchecked(Box::into_raw(Box::new(Node::new(element))));
+                    let old_tail = self.list.tail;
+                    self.list.splice_nodes(old_tail, None, new_node, new_node, 1);
+                }
                 return;
             };
 
-            // Unwrap is asserted to return `Some(&node)` from the cursor node.
-            let &Node {
-                value: _,
-                next: _,
-                prev,
-            } = cursor_node.as_ref().unwrap();
+            let prev = cursor_node.as_ref().prev;
 
             // The new node is created on the heap and is linked to
             // its prev and next nodes.
             let mut ptr = Box::new(Node::new(element));
             ptr.as_mut().link_nodes(Some(cursor_node), prev);
 
-            let new_node = std::boxed::Box::<Node<T>>::into_raw(ptr);
+            let new_node = NonNull::new_unchecked(Box::into_raw(ptr));
 
             // The new node is inserted before the cursor so it becomes the cursor's `prev`.
-            (*cursor_node).prev = Some(new_node);
+            cursor_node.as_mut().prev = Some(new_node);
 
             // The cursor's former prev node becomes linked to the new node,
             // with the new node being the next.
-            if let Some(prev_node) = prev {
-                (*prev_node).link_next(Some(new_node));
+            if let Some(mut prev_node) = prev {
+                prev_node.as_mut().link_next(Some(new_node));
             }
 
             // When insert_before is called while the cursor is at the head,
@@ -274,15 +465,148 @@ impl<'a, T> Cursor<'a, T> {
             }
 
             self.list.len += 1;
+
+            // The cursor's own node didn't move, but a node was just added
+            // in front of it, so its distance from the front grew by one.
+            self.index = self.index.map(|i| i + 1);
+        }
+    }
+
+    /// Split the list after the cursor, returning everything past it as a new
+    /// list. The cursor keeps the front portion and stays on the same element.
+    /// At the ghost position this splits at the front of the whole list.
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        match self.node {
+            None => std::mem::take(self.list),
+            Some(mut node) => unsafe {
+                match node.as_ref().next {
+                    None => LinkedList::new(),
+                    Some(mut next_node) => {
+                        let old_tail = self.list.tail.unwrap();
+                        // The cursor's own index tells us exactly how many
+                        // nodes stay behind, so the split-off length is
+                        // available without walking the severed chain.
+                        let tail_len = self.list.len - self.index.unwrap() - 1;
+
+                        node.as_mut().next = None;
+                        next_node.as_mut().prev = None;
+
+                        self.list.tail = Some(node);
+                        self.list.len -= tail_len;
+
+                        let mut tail_list = LinkedList::new();
+                        tail_list.len = tail_len;
+                        tail_list.head = Some(next_node);
+                        tail_list.tail = Some(old_tail);
+
+                        tail_list
+                    }
+                }
+            },
+        }
+    }
+
+    /// Split the list before the cursor, returning everything before it as a
+    /// new list. The cursor keeps the back portion and stays on the same
+    /// element. At the ghost position this splits at the back of the whole list.
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        match self.node {
+            None => std::mem::take(self.list),
+            Some(mut node) => unsafe {
+                match node.as_ref().prev {
+                    None => LinkedList::new(),
+                    Some(mut prev_node) => {
+                        let old_head = self.list.head.unwrap();
+                        // The cursor's own index is exactly the number of
+                        // nodes ahead of it, so the split-off length is
+                        // available without walking the severed chain.
+                        let front_len = self.index.unwrap();
+
+                        node.as_mut().prev = None;
+                        prev_node.as_mut().next = None;
+
+                        self.list.head = Some(node);
+                        self.list.len -= front_len;
+
+                        let mut front_list = LinkedList::new();
+                        front_list.len = front_len;
+                        front_list.head = Some(old_head);
+                        front_list.tail = Some(prev_node);
+
+                        // The cursor's node is now the head of `self.list`.
+                        self.index = Some(0);
+
+                        front_list
+                    }
+                }
+            },
+        }
+    }
+
+    /// Splice `other` into the list immediately after the cursor in O(1),
+    /// leaving `other` empty. At the ghost position this splices at the
+    /// front of the whole list.
+    pub fn splice_after(&mut self, mut other: LinkedList<T>) {
+        // An empty `other` has no nodes to relink, so there's nothing to do.
+        let (Some(other_head), Some(other_tail)) = (other.head.take(), other.tail.take()) else {
+            return;
+        };
+        let other_len = other.len;
+        other.len = 0;
+
+        unsafe {
+            match self.node {
+                None => {
+                    let old_head = self.list.head;
+                    self.list
+                        .splice_nodes(None, old_head, other_head, other_tail, other_len);
+                }
+                Some(node) => {
+                    let next = node.as_ref().next;
+                    self.list
+                        .splice_nodes(Some(node), next, other_head, other_tail, other_len);
+                }
+            }
+        }
+    }
+
+    /// Splice `other` into the list immediately before the cursor in O(1),
+    /// leaving `other` empty. At the ghost position this splices at the
+    /// back of the whole list.
+    pub fn splice_before(&mut self, mut other: LinkedList<T>) {
+        // An empty `other` has no nodes to relink, so there's nothing to do.
+        let (Some(other_head), Some(other_tail)) = (other.head.take(), other.tail.take()) else {
+            return;
+        };
+        let other_len = other.len;
+        other.len = 0;
+
+        unsafe {
+            match self.node {
+                None => {
+                    let old_tail = self.list.tail;
+                    self.list
+                        .splice_nodes(old_tail, None, other_head, other_tail, other_len);
+                }
+                Some(node) => {
+                    let prev = node.as_ref().prev;
+                    self.list
+                        .splice_nodes(prev, Some(node), other_head, other_tail, other_len);
+
+                    // `other_len` more nodes now sit in front of the cursor.
+                    self.index = self.index.map(|i| i + other_len);
+                }
+            }
         }
     }
 
     // This creates the first node in the linked list. Memory is always heap allocated and the
     // pointer is then returned. The first node is naturally the head and tail of the list.
-    fn insert_first(&mut self, element: T) -> *mut Node<T> {
+    fn insert_first(&mut self, element: T) -> NonNull<Node<T>> {
         let new_node = Node::new(element);
-        let node_ptr = std::boxed::Box::<Node<T>>::into_raw(Box::new(new_node));
+        let node_ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(new_node))) };
         self.node = Some(node_ptr);
+        self.index = Some(0);
         self.list.head = Some(node_ptr);
         self.list.tail = Some(node_ptr);
         self.list.len += 1;
@@ -292,10 +616,15 @@ impl<'a, T> Cursor<'a, T> {
 }
 
 pub struct Iter<'a, T> {
-    next: Option<*mut Node<T>>,
+    next: Option<NonNull<Node<T>>>,
+    next_back: Option<NonNull<Node<T>>>,
+    len: usize,
     marker: PhantomData<&'a LinkedList<T>>,
 }
 
+unsafe impl<'a, T: Sync> Send for Iter<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Iter<'a, T> {}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
@@ -303,21 +632,220 @@ impl<'a, T> Iterator for Iter<'a, T> {
         // The iterator will continue to move forward
         // so long as next points to an existing node.
         // It will short-circuit once it hits the first `None`.
+        if self.len == 0 {
+            return None;
+        }
+
         let next_node = self.next?;
 
         unsafe {
-            let node = &(*next_node);
+            let node = next_node.as_ref();
 
+            self.len -= 1;
             self.next = node.next;
 
             Some(&node.value)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
 }
 
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Mirrors `next`, but walks from the back pointer towards `prev`
+        // so the two ends meet in the middle and then stop.
+        if self.len == 0 {
+            return None;
+        }
+
+        let next_node = self.next_back?;
+
+        unsafe {
+            let node = next_node.as_ref();
+
+            self.len -= 1;
+            self.next_back = node.prev;
+
+            Some(&node.value)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+pub struct IterMut<'a, T> {
+    next: Option<NonNull<Node<T>>>,
+    next_back: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut LinkedList<T>>,
+}
+
+unsafe impl<'a, T: Send> Send for IterMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for IterMut<'a, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let mut next_node = self.next?;
+
+        unsafe {
+            let node = next_node.as_mut();
+
+            self.len -= 1;
+            self.next = node.next;
+
+            Some(&mut node.value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let mut next_node = self.next_back?;
+
+        unsafe {
+            let node = next_node.as_mut();
+
+            self.len -= 1;
+            self.next_back = node.prev;
+
+            Some(&mut node.value)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+/// An owning iterator, yielding elements by value, that pops each node
+/// from the front (or back) of the list as it's consumed.
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.cursor_front().take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.cursor_back().take()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
 impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
         let mut cursor = self.cursor_front();
         while cursor.take().is_some() {}
     }
 }
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.cursor_back().insert_after(item);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(arr: [T; N]) -> Self {
+        arr.into_iter().collect()
+    }
+}